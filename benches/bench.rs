@@ -24,6 +24,7 @@ fn criterion_benchmark(c: &mut Criterion) {
                 .scalar_sum()
         })
     });
+    c.bench_function("vec fused dot", |b| b.iter(|| xs.scalar_dot(&ys)));
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -0,0 +1,204 @@
+use core::simd::{LaneCount, Mask, Simd, SimdElement, SimdPartialOrd, SupportedLaneCount};
+
+use num_traits::{NumCast, One, Zero};
+
+use crate::integer::SimdIntegerIterExt;
+use crate::load_padded;
+use crate::min_max_identities::MinMaxIdentities;
+use crate::num::SimdNumIterExt;
+use crate::ord::SimdOrdIterExt;
+
+/// A SIMD iterator with an explicit, arbitrary lane width.
+///
+/// Unlike [`SimdIter`](crate::SimdIter), which relies on `<[T]>::as_simd` and
+/// is therefore limited to power-of-two widths, this walks fixed `LANES`-sized
+/// windows from index zero and builds each vector with a masked load. That lets
+/// users target non-power-of-two widths (3, 5, 6, ...) via the
+/// `all_lane_counts` capability, matching a specific register width exactly.
+pub struct SimdIterExact<'a, T: SimdElement, const LANES: usize>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    data: &'a [T],
+}
+
+impl<'a, T: SimdElement, const LANES: usize> SimdIterExact<'a, T, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    pub(crate) fn new(data: &'a [T]) -> Self {
+        SimdIterExact { data }
+    }
+
+    /// Pads the trailing partial window with `value`, yielding an iterator over
+    /// full `Simd<T, LANES>` vectors that the crate's reductions consume directly.
+    pub fn padded_with(self, value: T) -> SimdIterExactPadded<'a, T, LANES> {
+        SimdIterExactPadded {
+            data: self.data,
+            pad_value: value,
+        }
+    }
+
+    /// Returns the sum of all the scalars in the iterator.
+    pub fn scalar_sum(self) -> T
+    where
+        T: Zero,
+        SimdIterExactPadded<'a, T, LANES>: SimdNumIterExt<Scalar = T>,
+    {
+        self.padded_with(T::zero()).scalar_sum()
+    }
+
+    /// Returns the product of all the scalars in the iterator.
+    pub fn scalar_product(self) -> T
+    where
+        T: One,
+        SimdIterExactPadded<'a, T, LANES>: SimdNumIterExt<Scalar = T>,
+    {
+        self.padded_with(T::one()).scalar_product()
+    }
+
+    /// Returns the min of all the scalars in the iterator.
+    pub fn scalar_min(self) -> Option<T>
+    where
+        T: MinMaxIdentities,
+        SimdIterExactPadded<'a, T, LANES>: SimdOrdIterExt<Scalar = T>,
+    {
+        self.padded_with(T::min_identity()).scalar_min()
+    }
+
+    /// Returns the max of all the scalars in the iterator.
+    pub fn scalar_max(self) -> Option<T>
+    where
+        T: MinMaxIdentities,
+        SimdIterExactPadded<'a, T, LANES>: SimdOrdIterExt<Scalar = T>,
+    {
+        self.padded_with(T::max_identity()).scalar_max()
+    }
+
+    /// Returns the bit-wise AND (`&`) reduction of all the scalars in the iterator.
+    pub fn scalar_reduce_and(self) -> Option<T>
+    where
+        T: Zero + core::ops::Not<Output = T>,
+        SimdIterExactPadded<'a, T, LANES>: SimdIntegerIterExt<Scalar = T>,
+    {
+        self.padded_with(!T::zero()).scalar_reduce_and()
+    }
+
+    /// Returns the bit-wise OR (`|`) reduction of all the scalars in the iterator.
+    pub fn scalar_reduce_or(self) -> Option<T>
+    where
+        T: Zero,
+        SimdIterExactPadded<'a, T, LANES>: SimdIntegerIterExt<Scalar = T>,
+    {
+        self.padded_with(T::zero()).scalar_reduce_or()
+    }
+
+    /// Returns the bit-wise XOR (`^`) reduction of all the scalars in the iterator.
+    pub fn scalar_reduce_xor(self) -> Option<T>
+    where
+        T: Zero,
+        SimdIterExactPadded<'a, T, LANES>: SimdIntegerIterExt<Scalar = T>,
+    {
+        self.padded_with(T::zero()).scalar_reduce_xor()
+    }
+}
+
+/// Yields the full windows of a [`SimdIterExact`], without padding the tail.
+impl<T: SimdElement, const LANES: usize> Iterator for SimdIterExact<'_, T, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Item = Simd<T, LANES>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.len() < LANES {
+            return None;
+        }
+        let (head, rest) = self.data.split_at(LANES);
+        self.data = rest;
+        Some(Simd::from_slice(head))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.data.len() / LANES;
+        (n, Some(n))
+    }
+}
+
+impl<T: SimdElement, const LANES: usize> ExactSizeIterator for SimdIterExact<'_, T, LANES> where
+    LaneCount<LANES>: SupportedLaneCount
+{
+}
+
+/// A [`SimdIterExact`] whose trailing partial window is padded to a full vector.
+pub struct SimdIterExactPadded<'a, T: SimdElement, const LANES: usize>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    data: &'a [T],
+    pad_value: T,
+}
+
+impl<T: SimdElement, const LANES: usize> Iterator for SimdIterExactPadded<'_, T, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+    T::Mask: NumCast,
+    Simd<T::Mask, LANES>: SimdPartialOrd<Mask = Mask<T::Mask, LANES>>,
+{
+    type Item = Simd<T, LANES>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            None
+        } else if self.data.len() >= LANES {
+            let (head, rest) = self.data.split_at(LANES);
+            self.data = rest;
+            Some(Simd::from_slice(head))
+        } else {
+            let vector = load_padded::<T, LANES>(self.data, self.pad_value);
+            self.data = &[];
+            Some(vector)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.data.len().div_ceil(LANES);
+        (n, Some(n))
+    }
+}
+
+impl<T: SimdElement, const LANES: usize> ExactSizeIterator for SimdIterExactPadded<'_, T, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+    Self: Iterator,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+    use proptest::prelude::*;
+
+    use crate::SimdIterable;
+
+    proptest! {
+        #[test]
+        fn test_scalar_sum_exact(xs in prop::collection::vec(0.0..1.0f32, 0..100)) {
+            // Width 5 is non-power-of-two, so `as_simd` cannot produce it; full
+            // windows are loaded directly and the padded tail goes through
+            // `load_padded`.
+            assert_relative_eq!(
+                xs.iter().sum::<f32>(),
+                xs.simd_iter_exact::<5>().scalar_sum(),
+                max_relative = 0.00001,
+            );
+        }
+    }
+
+    #[test]
+    fn loads_only_valid_lanes_for_tiny_input() {
+        // The width dwarfs the input: the padded load must read only the three
+        // valid lanes, never the 13 lanes past the end of the allocation.
+        assert_eq!(3.0f32, [1.0f32; 3].simd_iter_exact::<16>().scalar_sum());
+    }
+}
@@ -0,0 +1,242 @@
+use core::simd::{LaneCount, Mask, Simd, SimdElement, SimdPartialOrd, SupportedLaneCount};
+
+use num_traits::ToPrimitive;
+
+/// An extension trait that builds [`SimdIter`](crate::SimdIter)-like iterators
+/// over *scattered* data, so the crate's existing reductions (`scalar_sum`,
+/// `scalar_min`, `scalar_reduce_*`, ...) can run over indexed or strided access
+/// patterns without first copying the elements into a contiguous buffer.
+pub trait SimdGatherIterable<T: SimdElement> {
+    /// Iterates over `self[indices[0]], self[indices[1]], ...` in `LANES`-wide
+    /// vectors built with a SIMD gather. Lanes whose index is out of range (or
+    /// which fall in the padded tail) are filled with `default` rather than
+    /// dereferenced.
+    fn simd_gather<'a, I, const LANES: usize>(
+        &'a self,
+        indices: &'a [I],
+        default: T,
+    ) -> SimdGatherIter<'a, T, I, LANES>
+    where
+        LaneCount<LANES>: SupportedLaneCount;
+
+    /// Iterates over every `stride`-th element of `self` (`self[0], self[stride],
+    /// self[2 * stride], ...`) in `LANES`-wide vectors built with a SIMD gather.
+    /// Lanes past the end of the slice are filled with `default`.
+    fn simd_iter_strided<const LANES: usize>(
+        &self,
+        stride: usize,
+        default: T,
+    ) -> SimdStridedIter<T, LANES>
+    where
+        LaneCount<LANES>: SupportedLaneCount;
+}
+
+impl<T: SimdElement, U: AsRef<[T]>> SimdGatherIterable<T> for U {
+    fn simd_gather<'a, I, const LANES: usize>(
+        &'a self,
+        indices: &'a [I],
+        default: T,
+    ) -> SimdGatherIter<'a, T, I, LANES>
+    where
+        LaneCount<LANES>: SupportedLaneCount,
+    {
+        SimdGatherIter {
+            data: self.as_ref(),
+            indices,
+            default,
+        }
+    }
+
+    fn simd_iter_strided<const LANES: usize>(
+        &self,
+        stride: usize,
+        default: T,
+    ) -> SimdStridedIter<T, LANES>
+    where
+        LaneCount<LANES>: SupportedLaneCount,
+    {
+        assert!(stride > 0, "stride must be non-zero");
+        SimdStridedIter {
+            data: self.as_ref(),
+            pos: 0,
+            stride,
+            default,
+        }
+    }
+}
+
+/// A SIMD iterator that gathers elements of a slice at caller-supplied indices.
+///
+/// See [`SimdGatherIterable::simd_gather`].
+pub struct SimdGatherIter<'a, T: SimdElement, I, const LANES: usize>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    data: &'a [T],
+    indices: &'a [I],
+    default: T,
+}
+
+impl<T, I, const LANES: usize> Iterator for SimdGatherIter<'_, T, I, LANES>
+where
+    T: SimdElement,
+    I: ToPrimitive + Copy,
+    LaneCount<LANES>: SupportedLaneCount,
+    Simd<usize, LANES>: SimdPartialOrd<Mask = Mask<isize, LANES>>,
+{
+    type Item = Simd<T, LANES>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.indices.is_empty() {
+            return None;
+        }
+        let take = self.indices.len().min(LANES);
+        let (chunk, rest) = self.indices.split_at(take);
+        self.indices = rest;
+
+        // Padding lanes and indices that do not fit a `usize` get `usize::MAX`
+        // (never read, since they are masked out).
+        let idxs = Simd::<usize, LANES>::from_array(core::array::from_fn(|lane| {
+            chunk
+                .get(lane)
+                .and_then(ToPrimitive::to_usize)
+                .unwrap_or(usize::MAX)
+        }));
+        let iota = Simd::<usize, LANES>::from_array(core::array::from_fn(|i| i));
+        // Mask out padding lanes and indices that would read past the slice.
+        let enable = iota.simd_lt(Simd::splat(take)) & idxs.simd_lt(Simd::splat(self.data.len()));
+        Some(Simd::gather_select(
+            self.data,
+            enable,
+            idxs,
+            Simd::splat(self.default),
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.indices.len().div_ceil(LANES);
+        (n, Some(n))
+    }
+}
+
+impl<T, I, const LANES: usize> ExactSizeIterator for SimdGatherIter<'_, T, I, LANES>
+where
+    T: SimdElement,
+    I: ToPrimitive + Copy,
+    LaneCount<LANES>: SupportedLaneCount,
+    Simd<usize, LANES>: SimdPartialOrd<Mask = Mask<isize, LANES>>,
+{
+}
+
+/// A SIMD iterator that gathers every `stride`-th element of a slice.
+///
+/// See [`SimdGatherIterable::simd_iter_strided`].
+pub struct SimdStridedIter<'a, T: SimdElement, const LANES: usize>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    data: &'a [T],
+    pos: usize,
+    stride: usize,
+    default: T,
+}
+
+impl<T, const LANES: usize> Iterator for SimdStridedIter<'_, T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+    Simd<usize, LANES>: SimdPartialOrd<Mask = Mask<isize, LANES>>,
+{
+    type Item = Simd<T, LANES>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.data.len() {
+            return None;
+        }
+        let base = self.pos;
+        let stride = self.stride;
+        let idxs =
+            Simd::<usize, LANES>::from_array(core::array::from_fn(|lane| base + lane * stride));
+        let enable = idxs.simd_lt(Simd::splat(self.data.len()));
+        self.pos = base + LANES * stride;
+        Some(Simd::gather_select(
+            self.data,
+            enable,
+            idxs,
+            Simd::splat(self.default),
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = if self.pos >= self.data.len() {
+            0
+        } else {
+            (self.data.len() - self.pos).div_ceil(LANES * self.stride)
+        };
+        (n, Some(n))
+    }
+}
+
+impl<T, const LANES: usize> ExactSizeIterator for SimdStridedIter<'_, T, LANES>
+where
+    T: SimdElement,
+    LaneCount<LANES>: SupportedLaneCount,
+    Simd<usize, LANES>: SimdPartialOrd<Mask = Mask<isize, LANES>>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::{SimdGatherIterable, SimdStridedIter};
+
+    const LANES: usize = 8;
+
+    proptest! {
+        #[test]
+        fn test_simd_gather(
+            data in prop::collection::vec(any::<i32>(), 0..20),
+            // Indices span the slice and deliberately overshoot both ends, so
+            // out-of-range and negative lanes exercise the masked-out path.
+            indices in prop::collection::vec(-5i32..25, 0..40),
+        ) {
+            let default = -999;
+            let want: Vec<i32> = indices
+                .iter()
+                .map(|&i| {
+                    usize::try_from(i)
+                        .ok()
+                        .and_then(|u| data.get(u))
+                        .copied()
+                        .unwrap_or(default)
+                })
+                .collect();
+            // The gather pads its final vector out to `LANES`; only the first
+            // `indices.len()` lanes correspond to requested indices.
+            let got: Vec<i32> = data
+                .simd_gather::<_, LANES>(&indices, default)
+                .flat_map(|v| v.to_array())
+                .take(indices.len())
+                .collect();
+            prop_assert_eq!(want, got);
+        }
+
+        #[test]
+        fn test_simd_iter_strided(
+            data in prop::collection::vec(any::<i32>(), 0..40),
+            stride in 1usize..5,
+        ) {
+            let default = -999;
+            let want: Vec<i32> = (0..data.len()).step_by(stride).map(|i| data[i]).collect();
+            let iter: SimdStridedIter<i32, LANES> = data.simd_iter_strided(stride, default);
+            let len = iter.len();
+            let got: Vec<i32> = iter
+                .flat_map(|v| v.to_array())
+                .take(want.len())
+                .collect();
+            prop_assert_eq!(&want, &got);
+            prop_assert_eq!(len, want.len().div_ceil(LANES));
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use core::simd::{LaneCount, Simd, SimdElement, SimdInt, SimdUint, SupportedLaneCount};
 
-use num_traits::PrimInt;
+use num_traits::{One, PrimInt, Zero};
 
 /// A SIMD vector with integer elements (i.e. `SimdInt` or `SimdUint`).
 pub trait SimdInteger:
@@ -14,6 +14,11 @@ core::ops::BitAnd<Output=Self>
     fn reduce_and(self) -> Self::Scalar;
     fn reduce_or(self) -> Self::Scalar;
     fn reduce_xor(self) -> Self::Scalar;
+
+    fn saturating_add(self, other: Self) -> Self;
+    fn saturating_mul(self, other: Self) -> Self;
+    fn reduce_saturating_sum(self) -> Self::Scalar;
+    fn reduce_saturating_product(self) -> Self::Scalar;
 }
 
 macro_rules! impl_simd_integer {
@@ -35,6 +40,26 @@ macro_rules! impl_simd_integer {
             fn reduce_xor(self) -> $elem {
                 <Self as $trait_>::reduce_xor(self)
             }
+
+            fn saturating_add(self, other: Self) -> Self {
+                <Self as $trait_>::saturating_add(self, other)
+            }
+
+            fn saturating_mul(self, other: Self) -> Self {
+                <Self as $trait_>::saturating_mul(self, other)
+            }
+
+            fn reduce_saturating_sum(self) -> $elem {
+                self.to_array()
+                    .into_iter()
+                    .fold(0 as $elem, |acc, x| acc.saturating_add(x))
+            }
+
+            fn reduce_saturating_product(self) -> $elem {
+                self.to_array()
+                    .into_iter()
+                    .fold(1 as $elem, |acc, x| acc.saturating_mul(x))
+            }
         }
     };
 }
@@ -62,6 +87,16 @@ pub trait SimdIntegerIterExt {
 
     /// Returns the bit-wise XOR (`^`) reduction of all the scalars in the iterator.
     fn scalar_reduce_xor(self) -> Option<Self::Scalar>;
+
+    /// Returns the saturating sum of all the scalars in the iterator.
+    ///
+    /// Saturation is applied at every accumulation step, not just at the end, so
+    /// narrow types (`u8`, `i16`, ...) can be aggregated histogram-style without
+    /// widening. Returns the additive identity for an empty iterator.
+    fn scalar_saturating_sum(self) -> Self::Scalar;
+
+    /// Returns the saturating product of all the scalars in the iterator.
+    fn scalar_saturating_product(self) -> Self::Scalar;
 }
 
 impl<I, T, const LANES: usize> SimdIntegerIterExt for I
@@ -87,4 +122,45 @@ impl<I, T, const LANES: usize> SimdIntegerIterExt for I
         self.reduce(core::ops::BitXor::bitxor)
             .map(SimdInteger::reduce_xor)
     }
+
+    fn scalar_saturating_sum(self) -> T {
+        self.reduce(SimdInteger::saturating_add)
+            .map(SimdInteger::reduce_saturating_sum)
+            .unwrap_or_else(T::zero)
+    }
+
+    fn scalar_saturating_product(self) -> T {
+        self.reduce(SimdInteger::saturating_mul)
+            .map(SimdInteger::reduce_saturating_product)
+            .unwrap_or_else(T::one)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::SimdIterable;
+
+    proptest! {
+        #[test]
+        fn test_scalar_saturating_sum_u8(xs in prop::collection::vec(any::<u8>(), 0..200)) {
+            let want = xs.iter().fold(0u8, |acc, &x| acc.saturating_add(x));
+            prop_assert_eq!(want, xs.simd_iter().scalar_saturating_sum());
+        }
+
+        // Non-negative i16s keep saturation monotonic, so the lane-grouped SIMD
+        // fold and the scalar left-to-right fold agree even once they clamp.
+        #[test]
+        fn test_scalar_saturating_sum_i16(xs in prop::collection::vec(0i16..=i16::MAX, 0..200)) {
+            let want = xs.iter().fold(0i16, |acc, &x| acc.saturating_add(x));
+            prop_assert_eq!(want, xs.simd_iter().scalar_saturating_sum());
+        }
+
+        #[test]
+        fn test_scalar_saturating_product_i16(xs in prop::collection::vec(0i16..=i16::MAX, 0..200)) {
+            let want = xs.iter().fold(1i16, |acc, &x| acc.saturating_mul(x));
+            prop_assert_eq!(want, xs.simd_iter().scalar_saturating_product());
+        }
+    }
 }
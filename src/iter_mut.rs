@@ -0,0 +1,140 @@
+use core::simd::{LaneCount, Mask, Simd, SimdElement, SimdPartialOrd, SupportedLaneCount};
+
+use num_traits::NumCast;
+
+use crate::load_padded;
+
+/// A mutable SIMD iterator over a slice, yielding `&mut Simd<T, LANES>` for each
+/// aligned chunk so data can be transformed *in place* with vectorized closures
+/// rather than only reduced.
+///
+/// The unaligned prefix and postfix are not yielded by the [`Iterator`] impl;
+/// use [`for_each_vector`](Self::for_each_vector) to apply a closure across the
+/// whole slice, including the tail.
+pub struct SimdIterMut<'a, T: SimdElement, const LANES: usize>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    prefix: &'a mut [T],
+    vectors: &'a mut [Simd<T, LANES>],
+    postfix: &'a mut [T],
+}
+
+impl<'a, T: SimdElement, const LANES: usize> SimdIterMut<'a, T, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    pub(crate) fn new(values: &'a mut [T]) -> Self {
+        let (prefix, vectors, postfix) = values.as_simd_mut();
+        SimdIterMut {
+            prefix,
+            vectors,
+            postfix,
+        }
+    }
+
+    pub fn prefix(&mut self) -> &mut [T] {
+        self.prefix
+    }
+    pub fn postfix(&mut self) -> &mut [T] {
+        self.postfix
+    }
+
+    /// Applies `f` to every `LANES`-wide chunk of the slice, writing the result
+    /// back through the mutable borrow.
+    ///
+    /// The aligned middle is mapped directly; the unaligned prefix and postfix
+    /// are loaded into a masked vector so the *same* closure runs on the tail,
+    /// and only the in-range lanes are stored back — the padding lanes are never
+    /// written past the end of the allocation.
+    ///
+    /// ```
+    /// use core::simd::Simd;
+    /// use simd_iter::SimdIterable;
+    /// let mut xs = [1., 2., 3., 4., 5.];
+    /// xs.simd_iter_mut().for_each_vector(|v| *v *= Simd::splat(2.));
+    /// assert_eq!([2., 4., 6., 8., 10.], xs);
+    /// ```
+    pub fn for_each_vector<F>(self, mut f: F)
+    where
+        F: FnMut(&mut Simd<T, LANES>),
+        T::Mask: NumCast,
+        Simd<T::Mask, LANES>: SimdPartialOrd<Mask = Mask<T::Mask, LANES>>,
+    {
+        apply_tail(self.prefix, &mut f);
+        for vector in self.vectors.iter_mut() {
+            f(vector);
+        }
+        apply_tail(self.postfix, &mut f);
+    }
+}
+
+/// Runs `f` over the unaligned `values`, loading them into a masked vector and
+/// storing only the in-range lanes back.
+fn apply_tail<T, F, const LANES: usize>(values: &mut [T], f: &mut F)
+where
+    T: SimdElement,
+    F: FnMut(&mut Simd<T, LANES>),
+    T::Mask: NumCast,
+    LaneCount<LANES>: SupportedLaneCount,
+    Simd<T::Mask, LANES>: SimdPartialOrd<Mask = Mask<T::Mask, LANES>>,
+{
+    if values.is_empty() {
+        return;
+    }
+    // The pad value is irrelevant since those lanes are never stored back; reuse
+    // a real element so the closure never observes uninitialised padding.
+    let mut vector = load_padded::<T, LANES>(values, values[0]);
+    f(&mut vector);
+    let array = vector.to_array();
+    values.copy_from_slice(&array[..values.len()]);
+}
+
+impl<'a, T: SimdElement, const LANES: usize> Iterator for SimdIterMut<'a, T, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    type Item = &'a mut Simd<T, LANES>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let vectors = core::mem::take(&mut self.vectors);
+        if let Some((first, rest)) = vectors.split_first_mut() {
+            self.vectors = rest;
+            Some(first)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.vectors.len(), Some(self.vectors.len()))
+    }
+}
+
+impl<T: SimdElement, const LANES: usize> ExactSizeIterator for SimdIterMut<'_, T, LANES> where
+    LaneCount<LANES>: SupportedLaneCount
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use core::simd::Simd;
+
+    use proptest::prelude::*;
+
+    use crate::SimdIterable;
+
+    proptest! {
+        #[test]
+        fn test_for_each_vector(xs in prop::collection::vec(any::<i32>(), 0..100)) {
+            // Width 8 leaves an unaligned prefix/postfix for most lengths, so the
+            // masked tail writeback is exercised; equality over the whole vector
+            // confirms the tail lanes are written and the length is preserved.
+            let mut got = xs.clone();
+            got.simd_iter_mut_with_width::<8>()
+                .for_each_vector(|v| *v += Simd::splat(1));
+            let want: Vec<i32> = xs.iter().map(|x| x.wrapping_add(1)).collect();
+            prop_assert_eq!(want, got);
+        }
+    }
+}
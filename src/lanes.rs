@@ -0,0 +1,30 @@
+/// The lane count [`simd_iter`](crate::SimdIterable::simd_iter) picks by default
+/// for a given element type.
+///
+/// Rather than a single hardcoded width, each element type gets enough lanes to
+/// fill roughly the same register budget (32 bytes, i.e. a 256-bit vector), so
+/// `u8` is iterated 32 lanes at a time while `f64` uses only 4.
+pub trait DefaultLanes {
+    const DEFAULT_LANES: usize;
+}
+
+macro_rules! impl_default_lanes {
+    ($elem:ty) => {
+        impl DefaultLanes for $elem {
+            const DEFAULT_LANES: usize = 32 / core::mem::size_of::<$elem>();
+        }
+    };
+}
+
+impl_default_lanes!(f32);
+impl_default_lanes!(f64);
+impl_default_lanes!(i8);
+impl_default_lanes!(i16);
+impl_default_lanes!(i32);
+impl_default_lanes!(i64);
+impl_default_lanes!(isize);
+impl_default_lanes!(u8);
+impl_default_lanes!(u16);
+impl_default_lanes!(u32);
+impl_default_lanes!(u64);
+impl_default_lanes!(usize);
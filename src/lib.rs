@@ -1,29 +1,185 @@
 #![no_std]
 #![feature(portable_simd)]
+#![feature(all_lane_counts)]
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
 
 use core::simd::{LaneCount, Mask, Simd, SimdElement, SimdPartialOrd, SupportedLaneCount};
 
-use num_traits::{NumCast, One, Zero};
+use num_traits::{Num, NumCast, One, Zero};
 
+pub use crate::gather::{SimdGatherIter, SimdGatherIterable, SimdStridedIter};
+pub use crate::exact::{SimdIterExact, SimdIterExactPadded};
 pub use crate::integer::SimdIntegerIterExt;
+pub use crate::iter_mut::SimdIterMut;
+pub use crate::lanes::DefaultLanes;
 use crate::min_max_identities::MinMaxIdentities;
+use crate::num::SimdNum;
 pub use crate::num::SimdNumIterExt;
-pub use crate::ord::SimdOrdIterExt;
+pub use crate::ord::{SimdOrdIterExt, SimdTotalOrdIterExt};
+pub use crate::predicate::SimdPredicateReductions;
 
+mod exact;
+mod gather;
 mod integer;
+mod iter_mut;
+mod lanes;
 mod min_max_identities;
 mod num;
 mod ord;
+mod predicate;
 
 pub trait SimdIterable<T: SimdElement> {
-    // TODO better default num lanes?
-    fn simd_iter(&self) -> SimdIter<T, 32> {
-        self.simd_iter_with_width::<32>()
+    /// Returns a SIMD iterator whose lane width is picked to suit the element
+    /// type (see [`DefaultLanes`]), so narrow elements are iterated wider.
+    fn simd_iter(&self) -> SimdIter<T, { T::DEFAULT_LANES }>
+    where
+        T: DefaultLanes,
+        LaneCount<{ T::DEFAULT_LANES }>: SupportedLaneCount,
+    {
+        self.simd_iter_with_width::<{ T::DEFAULT_LANES }>()
     }
 
     fn simd_iter_with_width<const LANES: usize>(&self) -> SimdIter<T, LANES>
     where
         LaneCount<LANES>: SupportedLaneCount;
+
+    /// Returns a SIMD iterator with an explicit, arbitrary lane width, including
+    /// non-power-of-two widths that `<[T]>::as_simd` cannot produce. See
+    /// [`SimdIterExact`].
+    fn simd_iter_exact<const LANES: usize>(&self) -> SimdIterExact<T, LANES>
+    where
+        Self: AsRef<[T]>,
+        LaneCount<LANES>: SupportedLaneCount,
+    {
+        SimdIterExact::new(self.as_ref())
+    }
+
+    /// Returns a mutable SIMD iterator over this slice, for transforming it in
+    /// place, with the same element-type-adaptive lane width as
+    /// [`simd_iter`](Self::simd_iter) (see [`DefaultLanes`]). See [`SimdIterMut`].
+    fn simd_iter_mut(&mut self) -> SimdIterMut<T, { T::DEFAULT_LANES }>
+    where
+        Self: AsMut<[T]>,
+        T: DefaultLanes,
+        LaneCount<{ T::DEFAULT_LANES }>: SupportedLaneCount,
+    {
+        self.simd_iter_mut_with_width::<{ T::DEFAULT_LANES }>()
+    }
+
+    fn simd_iter_mut_with_width<const LANES: usize>(&mut self) -> SimdIterMut<T, LANES>
+    where
+        Self: AsMut<[T]>,
+        LaneCount<LANES>: SupportedLaneCount,
+    {
+        SimdIterMut::new(self.as_mut())
+    }
+
+    /// Returns the dot product of this slice with `other`, i.e. the lane-accumulated
+    /// sum of the element-wise products `self[i] * other[i]`.
+    ///
+    /// The two operands are walked in lockstep in 32-lane chunks from index
+    /// zero, so no assumption is made about their relative alignment (unlike zipping
+    /// two independent [`SimdIter`]s, whose prefix/postfix splits need not line up).
+    /// Each pair of lanes is accumulated with a fused multiply-add
+    /// ([`SimdNum::mul_add`]), so floats use a single rounding (hardware FMA where
+    /// available) and no product vector is ever materialised. Returns `T::zero()`
+    /// for empty inputs; the shorter of the two lengths is used when they differ.
+    ///
+    /// ```
+    /// use simd_iter::SimdIterable;
+    /// assert_eq!(32., [1., 2., 3.].scalar_dot(&[4., 5., 6.]));
+    /// ```
+    fn scalar_dot(&self, other: &[T]) -> T
+    where
+        Self: AsRef<[T]>,
+        T: Num,
+        T::Mask: NumCast,
+        Simd<T::Mask, 32>: SimdPartialOrd<Mask = Mask<T::Mask, 32>>,
+        Simd<T, 32>: SimdNum<Scalar = T>,
+    {
+        zip_fold::<T, 32, _>(self.as_ref(), other, Simd::splat(T::zero()), |acc, a, b| {
+            a.mul_add(b, acc)
+        })
+        .reduce_sum()
+    }
+
+    /// Combines this slice with `other` lane-wise using `map`, then reduces the
+    /// accumulated vectors down to a single scalar sum.
+    ///
+    /// This is the shared engine behind [`scalar_dot`](Self::scalar_dot): both
+    /// operands are loaded in `LANES`-sized chunks (the final chunk padded with
+    /// zero on both sides so out-of-range lanes contribute nothing), `map` is
+    /// applied to each pair of vectors, and the results are summed into a single
+    /// accumulator before a final [`reduce_sum`](SimdNum::reduce_sum).
+    fn zip_map_reduce<const LANES: usize, F>(&self, other: &[T], map: F) -> T
+    where
+        Self: AsRef<[T]>,
+        LaneCount<LANES>: SupportedLaneCount,
+        T: Num,
+        T::Mask: NumCast,
+        Simd<T::Mask, LANES>: SimdPartialOrd<Mask = Mask<T::Mask, LANES>>,
+        Simd<T, LANES>: SimdNum<Scalar = T>,
+        F: Fn(Simd<T, LANES>, Simd<T, LANES>) -> Simd<T, LANES>,
+    {
+        zip_fold::<T, LANES, _>(self.as_ref(), other, Simd::splat(T::zero()), |acc, a, b| {
+            acc + map(a, b)
+        })
+        .reduce_sum()
+    }
+}
+
+/// Walks `xs` and `ys` in lockstep in `LANES`-sized chunks from index zero,
+/// folding each pair of vectors into `acc` with `combine`. The final chunk of
+/// each slice is loaded with [`load_padded`] (filling out-of-range lanes with
+/// zero), so the two operands need not share an alignment the way zipping two
+/// [`SimdIter`]s would require. The shared engine behind
+/// [`scalar_dot`](SimdIterable::scalar_dot) and
+/// [`zip_map_reduce`](SimdIterable::zip_map_reduce).
+pub(crate) fn zip_fold<T, const LANES: usize, F>(
+    xs: &[T],
+    ys: &[T],
+    init: Simd<T, LANES>,
+    combine: F,
+) -> Simd<T, LANES>
+where
+    T: SimdElement + Num,
+    T::Mask: NumCast,
+    LaneCount<LANES>: SupportedLaneCount,
+    Simd<T::Mask, LANES>: SimdPartialOrd<Mask = Mask<T::Mask, LANES>>,
+    F: Fn(Simd<T, LANES>, Simd<T, LANES>, Simd<T, LANES>) -> Simd<T, LANES>,
+{
+    let len = xs.len().min(ys.len());
+    let mut acc = init;
+    let mut i = 0;
+    while i < len {
+        let end = (i + LANES).min(len);
+        let a = load_padded::<T, LANES>(&xs[i..end], T::zero());
+        let b = load_padded::<T, LANES>(&ys[i..end], T::zero());
+        acc = combine(acc, a, b);
+        i = end;
+    }
+    acc
+}
+
+/// Loads up to `LANES` scalars from the front of `values`, filling any lanes
+/// beyond `values.len()` with `pad_value`.
+///
+/// Unlike [`SimdIterPadded::next`], which reads a whole vector from inside its
+/// backing buffer and masks the overshoot, this is reachable from safe code with
+/// a caller-chosen `LANES` (via [`simd_iter_exact`](SimdIterable::simd_iter_exact)),
+/// so it must never touch memory past `values.len()`: only the in-range lanes are
+/// read, the rest take `pad_value`.
+pub(crate) fn load_padded<T: SimdElement, const LANES: usize>(
+    values: &[T],
+    pad_value: T,
+) -> Simd<T, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    Simd::from_array(core::array::from_fn(|i| {
+        values.get(i).copied().unwrap_or(pad_value)
+    }))
 }
 
 pub struct SimdIter<'a, T: SimdElement, const LANES: usize>
@@ -54,6 +210,12 @@ where
         self.postfix
     }
 
+    /// The unaligned prefix, aligned vectors, and unaligned postfix backing this
+    /// iterator, each borrowing for the iterator's full lifetime.
+    pub(crate) fn parts(&self) -> (&'a [T], &'a [Simd<T, LANES>], &'a [T]) {
+        (self.prefix, self.vectors, self.postfix)
+    }
+
     pub fn padded_with(self, value: T) -> SimdIterPadded<'a, T, LANES> {
         SimdIterPadded {
             inner: self,
@@ -61,6 +223,30 @@ where
         }
     }
 
+    /// Reduces the iterator over an arbitrary monoid.
+    ///
+    /// `vector_op` combines two lanes-wide vectors, `horizontal_op` collapses the
+    /// final accumulator to a scalar, and `identity_lane` is both the seed and
+    /// the value the prefix/postfix are padded with — it must be the identity of
+    /// `vector_op` so the padding lanes leave the result unchanged. All the
+    /// built-in reductions share this shape; for example `scalar_sum` is
+    /// `reduce_with(T::zero(), Add::add, SimdNum::reduce_sum)`. It lets users
+    /// express custom reductions — lane-wise saturating ops, min-of-absolute
+    /// value, log-sum-exp building blocks — without the crate pre-committing to
+    /// every operation.
+    pub fn reduce_with<F, G>(self, identity_lane: T, vector_op: F, horizontal_op: G) -> T
+    where
+        T::Mask: NumCast,
+        Simd<T::Mask, LANES>: SimdPartialOrd<Mask = Mask<T::Mask, LANES>>,
+        F: Fn(Simd<T, LANES>, Simd<T, LANES>) -> Simd<T, LANES>,
+        G: FnOnce(Simd<T, LANES>) -> T,
+    {
+        let acc = self
+            .padded_with(identity_lane)
+            .fold(Simd::splat(identity_lane), vector_op);
+        horizontal_op(acc)
+    }
+
     /// Returns the sum of all the scalars in the iterator, including the prefix and postfix.
     ///
     /// ```
@@ -70,9 +256,11 @@ where
     pub fn scalar_sum(self) -> T
     where
         T: Zero,
-        SimdIterPadded<'a, T, LANES>: SimdNumIterExt<Scalar = T>,
+        T::Mask: NumCast,
+        Simd<T::Mask, LANES>: SimdPartialOrd<Mask = Mask<T::Mask, LANES>>,
+        Simd<T, LANES>: SimdNum<Scalar = T>,
     {
-        self.padded_with(T::zero()).scalar_sum()
+        self.reduce_with(T::zero(), core::ops::Add::add, SimdNum::reduce_sum)
     }
 
     /// Returns the product of all the scalars in the iterator, including the prefix and postfix.
@@ -89,6 +277,40 @@ where
         self.padded_with(T::one()).scalar_product()
     }
 
+    /// Returns the compensated (Kahan/Neumaier) sum of all the scalars in the
+    /// iterator, including the prefix and postfix.
+    ///
+    /// ```
+    /// use simd_iter::SimdIterable;
+    /// assert_eq!(15., [1., 2., 3., 4., 5.].simd_iter().scalar_sum_compensated());
+    /// ```
+    pub fn scalar_sum_compensated(self) -> T
+    where
+        T: Zero,
+        SimdIterPadded<'a, T, LANES>: SimdNumIterExt<Scalar = T>,
+    {
+        self.padded_with(T::zero()).scalar_sum_compensated()
+    }
+
+    /// Returns the arithmetic mean of all the scalars in the iterator, using
+    /// compensated summation for accuracy. Returns `T::zero()` for empty input.
+    ///
+    /// ```
+    /// use simd_iter::SimdIterable;
+    /// assert_eq!(3., [1., 2., 3., 4., 5.].simd_iter().scalar_mean());
+    /// ```
+    pub fn scalar_mean(self) -> T
+    where
+        T: Zero + NumCast + core::ops::Div<Output = T>,
+        SimdIterPadded<'a, T, LANES>: SimdNumIterExt<Scalar = T>,
+    {
+        let len = self.prefix.len() + self.vectors.len() * LANES + self.postfix.len();
+        if len == 0 {
+            return T::zero();
+        }
+        self.scalar_sum_compensated() / <T as NumCast>::from(len).unwrap()
+    }
+
     /// Returns the min of all the scalars in the iterator, including the prefix and postfix.
     ///
     /// ```
@@ -117,6 +339,58 @@ where
         self.padded_with(T::max_identity()).scalar_max()
     }
 
+    /// Returns the saturating sum of all the scalars in the iterator, including
+    /// the prefix and postfix.
+    ///
+    /// ```
+    /// use simd_iter::SimdIterable;
+    /// assert_eq!(255u8, [200, 100, 50].simd_iter().scalar_saturating_sum());
+    /// ```
+    pub fn scalar_saturating_sum(self) -> T
+    where
+        T: Zero,
+        SimdIterPadded<'a, T, LANES>: SimdIntegerIterExt<Scalar = T>,
+    {
+        self.padded_with(T::zero()).scalar_saturating_sum()
+    }
+
+    /// Returns the saturating product of all the scalars in the iterator,
+    /// including the prefix and postfix.
+    pub fn scalar_saturating_product(self) -> T
+    where
+        T: One,
+        SimdIterPadded<'a, T, LANES>: SimdIntegerIterExt<Scalar = T>,
+    {
+        self.padded_with(T::one()).scalar_saturating_product()
+    }
+
+    /// Returns the total-order (NaN-safe) min of all the scalars in the
+    /// iterator, including the prefix and postfix. See [`SimdTotalOrdIterExt`].
+    pub fn scalar_min_total(self) -> Option<T>
+    where
+        T: MinMaxIdentities,
+        SimdIterPadded<'a, T, LANES>: SimdTotalOrdIterExt<Scalar = T>,
+    {
+        // `±inf` is not a total-order extremum (NaNs lie beyond it), so pad with
+        // an element of the input, which is an identity for min.
+        let pad = self.prefix.first().or(self.postfix.first()).copied();
+        self.padded_with(pad.unwrap_or_else(T::min_identity))
+            .scalar_min_total()
+    }
+
+    /// Returns the total-order (NaN-safe) max of all the scalars in the
+    /// iterator, including the prefix and postfix. See [`SimdTotalOrdIterExt`].
+    pub fn scalar_max_total(self) -> Option<T>
+    where
+        T: MinMaxIdentities,
+        SimdIterPadded<'a, T, LANES>: SimdTotalOrdIterExt<Scalar = T>,
+    {
+        // As in `scalar_min_total`, pad with an element of the input.
+        let pad = self.prefix.first().or(self.postfix.first()).copied();
+        self.padded_with(pad.unwrap_or_else(T::max_identity))
+            .scalar_max_total()
+    }
+
     /// Returns the bit-wise AND (`&`) reduction of all the scalars in the iterator, including the prefix and postfix.
     ///
     /// ```
@@ -303,5 +577,38 @@ mod tests {
         fn test_scalar_reduce_xor(xs in prop::collection::vec(any::<i32>(), 0..1000)) {
             assert_eq!(xs.iter().cloned().reduce(core::ops::BitXor::bitxor), xs.simd_iter().scalar_reduce_xor());
         }
+
+        #[test]
+        fn test_scalar_sum_compensated(
+            // Mix large and tiny magnitudes over a long input so the naive
+            // running sum sheds low-order bits that compensation should recover.
+            xs in prop::collection::vec(
+                prop_oneof![-1e9f32..1e9, -1.0f32..1.0, -1e-6f32..1e-6],
+                100..1000,
+            ),
+        ) {
+            // An `f64` running sum is effectively exact for these `f32` inputs.
+            let reference: f64 = xs.iter().map(|&x| x as f64).sum();
+            let plain_err = (xs.simd_iter().scalar_sum() as f64 - reference).abs();
+            let comp_err = (xs.simd_iter().scalar_sum_compensated() as f64 - reference).abs();
+            // Compensated summation must never be worse than the naive fold
+            // (equal when there is nothing to recover); a slack proportional to
+            // the magnitude absorbs the unavoidable final `f32` rounding.
+            let slack = 1e-3 * reference.abs().max(1.0);
+            prop_assert!(comp_err <= plain_err + slack, "comp {comp_err} > plain {plain_err}");
+        }
+
+        #[test]
+        fn test_reduce_with(xs in prop::collection::vec(any::<i32>(), 0..100)) {
+            use core::simd::{SimdInt, SimdOrd};
+
+            // A custom (min, i32::MAX) monoid: the non-zero identity catches any
+            // prefix/postfix padding that leaks a zero into the reduction.
+            let got = xs
+                .simd_iter_with_width::<8>()
+                .reduce_with(i32::MAX, SimdOrd::simd_min, SimdInt::reduce_min);
+            let want = xs.iter().copied().min().unwrap_or(i32::MAX);
+            prop_assert_eq!(want, got);
+        }
     }
 }
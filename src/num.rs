@@ -1,4 +1,6 @@
-use core::simd::{LaneCount, Simd, SimdElement, SimdFloat, SimdInt, SimdUint, SupportedLaneCount};
+use core::simd::{
+    LaneCount, Simd, SimdElement, SimdFloat, SimdInt, SimdUint, StdFloat, SupportedLaneCount,
+};
 
 use num_traits::{Num, NumOps};
 
@@ -8,10 +10,15 @@ pub trait SimdNum: NumOps + Sized {
 
     fn reduce_sum(self) -> Self::Scalar;
     fn reduce_product(self) -> Self::Scalar;
+
+    /// Lane-wise fused multiply-add, `self * a + b`. For floats this is a single
+    /// rounding (hardware FMA where available); for integers it falls back to an
+    /// ordinary `self * a + b`.
+    fn mul_add(self, a: Self, b: Self) -> Self;
 }
 
 macro_rules! impl_simd_num {
-    ($elem:ty, $trait_:ty) => {
+    ($elem:ty, $trait_:ty, $mul_add:expr) => {
         impl<const LANES: usize> SimdNum for Simd<$elem, LANES>
         where
             LaneCount<LANES>: SupportedLaneCount,
@@ -25,22 +32,26 @@ macro_rules! impl_simd_num {
             fn reduce_product(self) -> $elem {
                 <Self as $trait_>::reduce_product(self)
             }
+
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                $mul_add(self, a, b)
+            }
         }
     };
 }
 
-impl_simd_num!(f32, SimdFloat);
-impl_simd_num!(f64, SimdFloat);
-impl_simd_num!(i8, SimdInt);
-impl_simd_num!(i16, SimdInt);
-impl_simd_num!(i32, SimdInt);
-impl_simd_num!(i64, SimdInt);
-impl_simd_num!(isize, SimdInt);
-impl_simd_num!(u8, SimdUint);
-impl_simd_num!(u16, SimdUint);
-impl_simd_num!(u32, SimdUint);
-impl_simd_num!(u64, SimdUint);
-impl_simd_num!(usize, SimdUint);
+impl_simd_num!(f32, SimdFloat, |x: Self, a, b| StdFloat::mul_add(x, a, b));
+impl_simd_num!(f64, SimdFloat, |x: Self, a, b| StdFloat::mul_add(x, a, b));
+impl_simd_num!(i8, SimdInt, |x: Self, a, b| x * a + b);
+impl_simd_num!(i16, SimdInt, |x: Self, a, b| x * a + b);
+impl_simd_num!(i32, SimdInt, |x: Self, a, b| x * a + b);
+impl_simd_num!(i64, SimdInt, |x: Self, a, b| x * a + b);
+impl_simd_num!(isize, SimdInt, |x: Self, a, b| x * a + b);
+impl_simd_num!(u8, SimdUint, |x: Self, a, b| x * a + b);
+impl_simd_num!(u16, SimdUint, |x: Self, a, b| x * a + b);
+impl_simd_num!(u32, SimdUint, |x: Self, a, b| x * a + b);
+impl_simd_num!(u64, SimdUint, |x: Self, a, b| x * a + b);
+impl_simd_num!(usize, SimdUint, |x: Self, a, b| x * a + b);
 
 /// An extension trait for `Iterator`s over `SimdNum`s.
 pub trait SimdNumIterExt {
@@ -51,6 +62,16 @@ pub trait SimdNumIterExt {
 
     /// Returns the product of all the scalars in the iterator.
     fn scalar_product(self) -> Self::Scalar;
+
+    /// Returns the sum of all the scalars in the iterator, using lane-wise
+    /// Kahan/Neumaier compensated summation.
+    ///
+    /// This keeps the SIMD throughput of [`scalar_sum`](Self::scalar_sum) while
+    /// cutting the accumulated rounding error from `O(n·ε)` to effectively
+    /// `O(ε)`, which matters for long `f32`/`f64` inputs. A running `sum` and
+    /// compensation `c` are carried per lane; a final scalar Kahan pass folds
+    /// the per-lane residuals together so they are not discarded.
+    fn scalar_sum_compensated(self) -> Self::Scalar;
 }
 
 impl<I, T, const LANES: usize> SimdNumIterExt for I
@@ -73,4 +94,34 @@ impl<I, T, const LANES: usize> SimdNumIterExt for I
             .map(SimdNum::reduce_product)
             .unwrap_or_else(T::one)
     }
+
+    fn scalar_sum_compensated(self) -> T {
+        let mut sum = Simd::<T, LANES>::splat(T::zero());
+        let mut c = Simd::<T, LANES>::splat(T::zero());
+        for x in self {
+            let y = x - c;
+            let t = sum + y;
+            c = (t - sum) - y;
+            sum = t;
+        }
+
+        // Second, scalar Kahan pass over the lanes, folding in the per-lane
+        // compensation so the residuals are carried through to the result.
+        let mut acc = T::zero();
+        let mut comp = T::zero();
+        for (s, c) in sum.to_array().into_iter().zip(c.to_array()) {
+            kahan_add(&mut acc, &mut comp, s);
+            kahan_add(&mut acc, &mut comp, T::zero() - c);
+        }
+        acc
+    }
+}
+
+/// One step of scalar Kahan summation: adds `value` to `acc`, routing the lost
+/// low-order bits through the compensation term `comp`.
+fn kahan_add<T: Num + Copy>(acc: &mut T, comp: &mut T, value: T) {
+    let y = value - *comp;
+    let t = *acc + y;
+    *comp = (t - *acc) - y;
+    *acc = t;
 }
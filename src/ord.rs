@@ -84,3 +84,118 @@ where
             .map(SimdOrdOrFloat::reduce_max)
     }
 }
+
+/// Total-order (NaN-safe) min/max reductions for floats.
+///
+/// Unlike [`scalar_min`](SimdOrdIterExt::scalar_min), which leans on the SIMD
+/// `min`/`max` instructions and mishandles NaN and `±0.0`, these implement the
+/// IEEE-754 `totalOrder` predicate: each lane is reinterpreted through
+/// `to_bits` as an ordered unsigned key (the sign bit is flipped for
+/// non-negative values, every bit inverted for negatives), the existing integer
+/// min/max reduction runs on those keys, and the winning key is mapped back with
+/// `from_bits`. NaNs therefore sort to the ends deterministically, which is what
+/// reproducible numeric pipelines want.
+pub trait SimdTotalOrdIterExt {
+    type Scalar;
+
+    /// Returns the total-order minimum of all the scalars in the iterator.
+    fn scalar_min_total(self) -> Option<Self::Scalar>;
+
+    /// Returns the total-order maximum of all the scalars in the iterator.
+    fn scalar_max_total(self) -> Option<Self::Scalar>;
+}
+
+macro_rules! impl_total_ord {
+    ($float:ty, $uint:ty, $shift:expr, $sign_bit:expr) => {
+        impl<I, const LANES: usize> SimdTotalOrdIterExt for I
+        where
+            I: Iterator<Item = Simd<$float, LANES>>,
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            type Scalar = $float;
+
+            fn scalar_min_total(self) -> Option<$float> {
+                self.map(to_ordered_key)
+                    .reduce(SimdOrd::simd_min)
+                    .map(|keys| from_ordered_key(SimdUint::reduce_min(keys)))
+            }
+
+            fn scalar_max_total(self) -> Option<$float> {
+                self.map(to_ordered_key)
+                    .reduce(SimdOrd::simd_max)
+                    .map(|keys| from_ordered_key(SimdUint::reduce_max(keys)))
+            }
+        }
+
+        /// Maps each float lane to its order-preserving unsigned key.
+        fn to_ordered_key<const LANES: usize>(values: Simd<$float, LANES>) -> Simd<$uint, LANES>
+        where
+            LaneCount<LANES>: SupportedLaneCount,
+        {
+            let bits = values.to_bits();
+            // `0` for non-negative lanes, all-ones for negative lanes.
+            let sign = Simd::splat(0) - (bits >> Simd::splat($shift));
+            bits ^ (sign | Simd::splat($sign_bit))
+        }
+
+        /// Inverts [`to_ordered_key`] for a single scalar key.
+        fn from_ordered_key(key: $uint) -> $float {
+            let mask = (key >> $shift).wrapping_sub(1) | $sign_bit;
+            <$float>::from_bits(key ^ mask)
+        }
+    };
+}
+
+mod total_ord_f32 {
+    use super::*;
+    impl_total_ord!(f32, u32, 31, 0x8000_0000);
+}
+
+mod total_ord_f64 {
+    use super::*;
+    impl_total_ord!(f64, u64, 63, 0x8000_0000_0000_0000);
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cmp::Ordering;
+
+    use proptest::prelude::*;
+
+    use crate::SimdIterable;
+
+    /// A strategy mixing arbitrary bit patterns with the special values
+    /// (`±0.0`, `±inf`, NaN) that distinguish total order from `simd_min`/`max`.
+    fn floats() -> impl Strategy<Value = f32> {
+        prop_oneof![
+            any::<f32>(),
+            Just(0.0),
+            Just(-0.0),
+            Just(f32::INFINITY),
+            Just(f32::NEG_INFINITY),
+            Just(f32::NAN),
+            Just(-f32::NAN),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn test_scalar_min_total(xs in prop::collection::vec(floats(), 0..100)) {
+            let want = xs
+                .iter()
+                .copied()
+                .reduce(|a, b| if a.total_cmp(&b) == Ordering::Greater { b } else { a });
+            // Compare bit patterns, since NaN and `±0.0` are not `==` to themselves.
+            prop_assert_eq!(want.map(f32::to_bits), xs.simd_iter().scalar_min_total().map(f32::to_bits));
+        }
+
+        #[test]
+        fn test_scalar_max_total(xs in prop::collection::vec(floats(), 0..100)) {
+            let want = xs
+                .iter()
+                .copied()
+                .reduce(|a, b| if a.total_cmp(&b) == Ordering::Less { b } else { a });
+            prop_assert_eq!(want.map(f32::to_bits), xs.simd_iter().scalar_max_total().map(f32::to_bits));
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use core::simd::{LaneCount, Mask, Simd, SimdElement, SimdPartialOrd, SupportedLaneCount};
+
+use num_traits::NumCast;
+
+use crate::{load_padded, SimdIter};
+
+/// An extension trait for boolean reductions over a SIMD predicate.
+///
+/// The predicate is any closure mapping a vector to a [`Mask`], typically built
+/// from a comparison such as `v.simd_gt(Simd::splat(threshold))`. Unlike the
+/// numeric reductions these run over the *unpadded* [`SimdIter`]: the partial
+/// prefix/postfix vectors have their out-of-range lanes forced to `false`
+/// before counting or testing, so a pad value can never spuriously match.
+pub trait SimdPredicateReductions<T: SimdElement, const LANES: usize>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    /// Returns the number of scalars for which `pred` is `true`.
+    ///
+    /// ```
+    /// use core::simd::{cmp::SimdPartialOrd, Simd};
+    /// use simd_iter::{SimdIterable, SimdPredicateReductions};
+    /// let n = [1, 5, 2, 9, 4].simd_iter().scalar_count(|v| v.simd_gt(Simd::splat(3)));
+    /// assert_eq!(3, n);
+    /// ```
+    fn scalar_count<F>(self, pred: F) -> usize
+    where
+        F: Fn(Simd<T, LANES>) -> Mask<T::Mask, LANES>;
+
+    /// Returns `true` if `pred` holds for at least one scalar, short-circuiting.
+    fn scalar_any<F>(self, pred: F) -> bool
+    where
+        F: Fn(Simd<T, LANES>) -> Mask<T::Mask, LANES>;
+
+    /// Returns `true` if `pred` holds for every scalar, short-circuiting.
+    fn scalar_all<F>(self, pred: F) -> bool
+    where
+        F: Fn(Simd<T, LANES>) -> Mask<T::Mask, LANES>;
+
+    /// Returns the index of the first scalar for which `pred` is `true`.
+    fn scalar_position<F>(self, pred: F) -> Option<usize>
+    where
+        F: Fn(Simd<T, LANES>) -> Mask<T::Mask, LANES>;
+
+    /// Returns the first scalar for which `pred` is `true`.
+    ///
+    /// ```
+    /// use core::simd::{cmp::SimdPartialOrd, Simd};
+    /// use simd_iter::{SimdIterable, SimdPredicateReductions};
+    /// let x = [1, 5, 2, 9, 4].simd_iter().scalar_find(|v| v.simd_gt(Simd::splat(3)));
+    /// assert_eq!(Some(5), x);
+    /// ```
+    fn scalar_find<F>(self, pred: F) -> Option<T>
+    where
+        F: Fn(Simd<T, LANES>) -> Mask<T::Mask, LANES>;
+}
+
+impl<'a, T, const LANES: usize> SimdPredicateReductions<T, LANES> for SimdIter<'a, T, LANES>
+where
+    T: SimdElement,
+    T::Mask: NumCast,
+    LaneCount<LANES>: SupportedLaneCount,
+    Simd<T::Mask, LANES>: SimdPartialOrd<Mask = Mask<T::Mask, LANES>>,
+{
+    fn scalar_count<F>(self, pred: F) -> usize
+    where
+        F: Fn(Simd<T, LANES>) -> Mask<T::Mask, LANES>,
+    {
+        let (prefix, vectors, postfix) = self.parts();
+        let mut count = 0;
+        if !prefix.is_empty() {
+            count += tail_mask(prefix, &pred).to_bitmask().count_ones() as usize;
+        }
+        for vector in vectors {
+            count += pred(*vector).to_bitmask().count_ones() as usize;
+        }
+        if !postfix.is_empty() {
+            count += tail_mask(postfix, &pred).to_bitmask().count_ones() as usize;
+        }
+        count
+    }
+
+    fn scalar_any<F>(self, pred: F) -> bool
+    where
+        F: Fn(Simd<T, LANES>) -> Mask<T::Mask, LANES>,
+    {
+        let (prefix, vectors, postfix) = self.parts();
+        (!prefix.is_empty() && tail_mask(prefix, &pred).any())
+            || vectors.iter().any(|vector| pred(*vector).any())
+            || (!postfix.is_empty() && tail_mask(postfix, &pred).any())
+    }
+
+    fn scalar_all<F>(self, pred: F) -> bool
+    where
+        F: Fn(Simd<T, LANES>) -> Mask<T::Mask, LANES>,
+    {
+        let (prefix, vectors, postfix) = self.parts();
+        tail_all(prefix, &pred)
+            && vectors.iter().all(|vector| pred(*vector).all())
+            && tail_all(postfix, &pred)
+    }
+
+    fn scalar_position<F>(self, pred: F) -> Option<usize>
+    where
+        F: Fn(Simd<T, LANES>) -> Mask<T::Mask, LANES>,
+    {
+        let (prefix, vectors, postfix) = self.parts();
+        let mut offset = 0;
+        if !prefix.is_empty() {
+            if let Some(lane) = tail_mask(prefix, &pred).first_set() {
+                return Some(offset + lane);
+            }
+            offset += prefix.len();
+        }
+        for vector in vectors {
+            if let Some(lane) = pred(*vector).first_set() {
+                return Some(offset + lane);
+            }
+            offset += LANES;
+        }
+        if !postfix.is_empty() {
+            if let Some(lane) = tail_mask(postfix, &pred).first_set() {
+                return Some(offset + lane);
+            }
+        }
+        None
+    }
+
+    fn scalar_find<F>(self, pred: F) -> Option<T>
+    where
+        F: Fn(Simd<T, LANES>) -> Mask<T::Mask, LANES>,
+    {
+        let (prefix, vectors, postfix) = self.parts();
+        if !prefix.is_empty() {
+            if let Some(lane) = tail_mask(prefix, &pred).first_set() {
+                return Some(prefix[lane]);
+            }
+        }
+        for vector in vectors {
+            if let Some(lane) = pred(*vector).first_set() {
+                return Some(vector.as_array()[lane]);
+            }
+        }
+        if !postfix.is_empty() {
+            if let Some(lane) = tail_mask(postfix, &pred).first_set() {
+                return Some(postfix[lane]);
+            }
+        }
+        None
+    }
+}
+
+/// The mask of a partial tail vector, with the out-of-range lanes forced to
+/// `false`. `values` must be non-empty and shorter than `LANES`.
+fn tail_mask<T, F, const LANES: usize>(values: &[T], pred: &F) -> Mask<T::Mask, LANES>
+where
+    T: SimdElement,
+    T::Mask: NumCast,
+    F: Fn(Simd<T, LANES>) -> Mask<T::Mask, LANES>,
+    LaneCount<LANES>: SupportedLaneCount,
+    Simd<T::Mask, LANES>: SimdPartialOrd<Mask = Mask<T::Mask, LANES>>,
+{
+    let vector = load_padded::<T, LANES>(values, values[0]);
+    let iota = Simd::from_array(core::array::from_fn(|i| {
+        <T::Mask as NumCast>::from(i).unwrap()
+    }));
+    let valid = iota.simd_lt(Simd::splat(
+        <T::Mask as NumCast>::from(values.len()).unwrap(),
+    ));
+    pred(vector) & valid
+}
+
+/// Whether `pred` holds for every in-range lane of a (possibly empty) tail.
+fn tail_all<T, F, const LANES: usize>(values: &[T], pred: &F) -> bool
+where
+    T: SimdElement,
+    T::Mask: NumCast,
+    F: Fn(Simd<T, LANES>) -> Mask<T::Mask, LANES>,
+    LaneCount<LANES>: SupportedLaneCount,
+    Simd<T::Mask, LANES>: SimdPartialOrd<Mask = Mask<T::Mask, LANES>>,
+{
+    if values.is_empty() {
+        return true;
+    }
+    let vector = load_padded::<T, LANES>(values, values[0]);
+    let iota = Simd::from_array(core::array::from_fn(|i| {
+        <T::Mask as NumCast>::from(i).unwrap()
+    }));
+    let valid = iota.simd_lt(Simd::splat(
+        <T::Mask as NumCast>::from(values.len()).unwrap(),
+    ));
+    // Every valid lane matches iff no valid lane fails the predicate.
+    !(valid & !pred(vector)).any()
+}
+
+#[cfg(test)]
+mod tests {
+    use core::simd::{cmp::SimdPartialOrd, Simd};
+
+    use proptest::prelude::*;
+
+    use crate::{SimdIterable, SimdPredicateReductions};
+
+    proptest! {
+        #[test]
+        fn test_scalar_count(xs in prop::collection::vec(any::<i32>(), 0..200), t in any::<i32>()) {
+            let want = xs.iter().filter(|&&x| x > t).count();
+            let got = xs.simd_iter().scalar_count(|v| v.simd_gt(Simd::splat(t)));
+            prop_assert_eq!(want, got);
+        }
+
+        #[test]
+        fn test_scalar_any(xs in prop::collection::vec(any::<i32>(), 0..200), t in any::<i32>()) {
+            let want = xs.iter().any(|&x| x > t);
+            let got = xs.simd_iter().scalar_any(|v| v.simd_gt(Simd::splat(t)));
+            prop_assert_eq!(want, got);
+        }
+
+        #[test]
+        fn test_scalar_all(xs in prop::collection::vec(any::<i32>(), 0..200), t in any::<i32>()) {
+            let want = xs.iter().all(|&x| x > t);
+            let got = xs.simd_iter().scalar_all(|v| v.simd_gt(Simd::splat(t)));
+            prop_assert_eq!(want, got);
+        }
+
+        #[test]
+        fn test_scalar_position(xs in prop::collection::vec(any::<i32>(), 0..200), t in any::<i32>()) {
+            let want = xs.iter().position(|&x| x > t);
+            let got = xs.simd_iter().scalar_position(|v| v.simd_gt(Simd::splat(t)));
+            prop_assert_eq!(want, got);
+        }
+
+        #[test]
+        fn test_scalar_find(xs in prop::collection::vec(any::<i32>(), 0..200), t in any::<i32>()) {
+            let want = xs.iter().copied().find(|&x| x > t);
+            let got = xs.simd_iter().scalar_find(|v| v.simd_gt(Simd::splat(t)));
+            prop_assert_eq!(want, got);
+        }
+    }
+}